@@ -9,9 +9,12 @@ use sp_runtime::MultiAddress;
 
 /// A smart contract meant to decentralize the permissionless creation of prediction markets between a
 /// small (up to around 10) amount of users.
-#[ink::contract]
+#[ink::contract(env = crate::ZeitDaoEnvironment)]
 mod zeit_dao {
-    use crate::{AssetManagerCall, PredictionMarketsCall, RuntimeCall, ZeitgeistAsset};
+    use crate::{
+        AssetManagerCall, ConfiguredRuntimeCall, HybridRouterCall, OutcomeReport,
+        PredictionMarketsCall, RuntimeCall, RuntimeConfig, SystemCall, ZeitgeistAsset,
+    };
     use ink::env::Error as EnvError;
     use ink::{prelude::vec::Vec, storage::Mapping};
 
@@ -33,9 +36,47 @@ mod zeit_dao {
 
     pub enum DAOAction {
         // Config
+        AddMember(AccountId),
+        RemoveMember(AccountId),
+        ChangeQuorum(u32),
+        Batch(Vec<StorableRuntimeAction>),
+        /// Transfers `balance` of `currency_id` (e.g. `ZeitgeistAsset::ForeignAsset`) out of
+        /// the treasury to `target`.
+        Distribute {
+            balance: u128,
+            target: AccountId,
+            currency_id: ZeitgeistAsset,
+        },
 
         // Runtime Actions
         RemarkWithEvent,
+        /// Reports the DAO (as oracle) into a market's winning outcome.
+        ReportMarket {
+            market_id: u128,
+            outcome: OutcomeReport,
+        },
+        /// Disputes a market's currently reported outcome.
+        DisputeMarket { market_id: u128 },
+        /// Moves a disputed market to resolution under `MarketDisputeMechanism::Authorized`.
+        ResolveMarket { market_id: u128 },
+        /// Buys into a market's outcome asset through the Hybrid Router.
+        Buy {
+            market_id: u128,
+            asset_count: u16,
+            asset: ZeitgeistAsset,
+            amount_in: u128,
+            max_price: u128,
+            orders: Vec<u128>,
+        },
+        /// Sells out of a market's outcome asset through the Hybrid Router.
+        Sell {
+            market_id: u128,
+            asset_count: u16,
+            asset: ZeitgeistAsset,
+            amount_in: u128,
+            max_price: u128,
+            orders: Vec<u128>,
+        },
     }
 
     #[derive(Debug, Clone, scale::Decode, scale::Encode)]
@@ -64,6 +105,21 @@ mod zeit_dao {
         action: DAOAction,
     }
 
+    /// Emitted after a `Buy`/`Sell` proposal is dispatched through the Hybrid Router.
+    ///
+    /// `call_runtime` only dispatches an extrinsic — it cannot hand back the dispatchable's
+    /// return data — so this event cannot report the router's actual `amount_out` or fee.
+    /// It only confirms that a trade for `market_id`/`asset` was dispatched; the requested
+    /// `amount_in`/`max_price` are already on the executed proposal, so they are not
+    /// repeated here. Index the runtime's own trade event (or query chain state) for the
+    /// real fill.
+    #[ink(event)]
+    pub struct PositionTraded {
+        id: u32,
+        market_id: u128,
+        asset: ZeitgeistAsset,
+    }
+
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum ZeitDAOError {
@@ -71,7 +127,13 @@ mod zeit_dao {
         OnlyMemberAllowed,
         OnlySelfAllowed,
         ProposalDoesNotExist,
+        ProposalAlreadyExecuted,
         NotEnoughVotesApproved,
+        InvalidProposalData,
+        QuorumExceedsMembers,
+        AssetBalanceQueryFailed,
+        BatchTooLarge,
+        BatchNestedTooDeep,
     }
 
     impl From<EnvError> for ZeitDAOError {
@@ -93,23 +155,37 @@ mod zeit_dao {
         votes: Mapping<(AccountId, u32), bool>,
         /// The number of aye votes needed before a proposal is accepted
         quorum: u32,
+        /// Whether a proposal has already been executed, so it cannot be replayed.
+        executed: Mapping<u32, bool>,
 
         /* Zeitgeist Components */
         proposals: Vec<StorableRuntimeAction>,
+        /// The pallet indices this instance dispatches `RuntimeCall`s under; see
+        /// `RuntimeConfig`.
+        runtime_config: RuntimeConfig,
     }
 
+    /// Mirrors the bounded multi-call pattern (`MaxCalls`) substrate's lottery pallet uses
+    /// to cap a single batch's dispatch weight.
+    const MAX_BATCH_LEN: usize = 16;
+    /// Bounds how deeply a `DAOAction::Batch` may nest another `Batch`, so a proposal can't
+    /// exhaust the wasm call stack when `execute` recurses through `dispatch_action`.
+    const MAX_BATCH_DEPTH: u8 = 4;
+
     impl ZeitDao {
         /// Constructor that initializes the `bool` value to the given `init_value`.
         #[ink(constructor)]
-        pub fn new(_quorum: u32, _members: Vec<AccountId>) -> Self {
+        pub fn new(_quorum: u32, _members: Vec<AccountId>, runtime_config: RuntimeConfig) -> Self {
             if _quorum > _members.len() as u32 {
                 panic!("Quorum must be less than or equal to length of members!");
             }
             Self {
                 members: _members,
                 votes: Mapping::default(),
+                executed: Mapping::default(),
                 proposals: Vec::default(),
                 quorum: _quorum,
+                runtime_config,
             }
         }
 
@@ -126,13 +202,11 @@ mod zeit_dao {
         pub fn test_asset_manager(&mut self) -> Result<(), ZeitDAOError> {
             // TODO: test to see if this works
             // Should send 1 ZTG to the user
-            self.env()
-                .call_runtime(&RuntimeCall::AssetManager(AssetManagerCall::Transfer {
-                    dest: self.env().caller().into(),
-                    currency_id: ZeitgeistAsset::Ztg,
-                    amount: 10_000_000_000,
-                }))
-                .map_err(Into::into)
+            self.dispatch_runtime_call(RuntimeCall::AssetManager(AssetManagerCall::Transfer {
+                dest: self.env().caller().into(),
+                currency_id: ZeitgeistAsset::Ztg,
+                amount: 10_000_000_000,
+            }))
         }
 
         pub fn test_create_market(&mut self) -> Result<(), ZeitDAOError> {
@@ -144,31 +218,28 @@ mod zeit_dao {
                 0xdd, 0x3d, 0x59, 0xac, 0x8c, 0x9a, 0x46, 0x1c, 0x7a, 0x68
             ];
 
-            self.env()
-                .call_runtime(&RuntimeCall::PredictionMarkets(
-                    PredictionMarketsCall::CreateCpmmMarketAndDeployAssets {
-                        base_asset: ZeitgeistAsset::Ztg,
-                        creator_fee: 1000,
-                        oracle: self.env().account_id(), // Puts self as oracle
-                        period: crate::MarketPeriod::Block(core::ops::Range {
-                            start: (self.env().block_number() + 1) as u64,
-                            end: (self.env().block_number() + 150) as u64,
-                        }),
-                        deadlines: crate::Deadlines {
-                            grace_period: 0,
-                            oracle_duration: 28_800,
-                            dispute_duration: 28_800,
-                        },
-                        metadata: crate::MultiHash::Sha3_384(sha3),
-                        market_type: crate::MarketType::Categorical(2),
-                        dispute_mechanism: crate::MarketDisputeMechanism::Authorized,
-                        swap_fee: 10000,
-                        amount: 1000,
-                        weights: Vec::from([0, 1]),
+            self.dispatch_runtime_call(RuntimeCall::PredictionMarkets(
+                PredictionMarketsCall::CreateCpmmMarketAndDeployAssets {
+                    base_asset: ZeitgeistAsset::Ztg,
+                    creator_fee: 1000,
+                    oracle: self.env().account_id(), // Puts self as oracle
+                    period: crate::MarketPeriod::Block(core::ops::Range {
+                        start: (self.env().block_number() + 1) as u64,
+                        end: (self.env().block_number() + 150) as u64,
+                    }),
+                    deadlines: crate::Deadlines {
+                        grace_period: 0,
+                        oracle_duration: 28_800,
+                        dispute_duration: 28_800,
                     },
-                ))
-                .map_err(Into::<ZeitDAOError>::into)?;
-            Ok(())
+                    metadata: crate::MultiHash::Sha3_384(sha3),
+                    market_type: crate::MarketType::Categorical(2),
+                    dispute_mechanism: crate::MarketDisputeMechanism::Authorized,
+                    swap_fee: 10000,
+                    amount: 1000,
+                    weights: Vec::from([0, 1]),
+                },
+            ))
         }
 
         // endregion
@@ -191,28 +262,74 @@ mod zeit_dao {
             Ok(())
         }
 
-        // TODO: implement propose, vote, execute by stealing from the multisig
-        // https://github.com/paritytech/ink-examples/blob/b5a5a554f85e9bd07d288ab319d14f15e6e509af/multisig/lib.rs
+        /// Tallies the votes cast on a proposal and, if `quorum` ayes have been reached,
+        /// dispatches the proposed action and marks the proposal as executed so it cannot
+        /// be replayed.
+        #[ink(message)]
+        pub fn execute(&mut self, id: u32) -> Result<(), ZeitDAOError> {
+            self.check_proposal_exists(id)?;
+            if self.executed.get(id).unwrap_or(false) {
+                return Err(ZeitDAOError::ProposalAlreadyExecuted);
+            }
+
+            let ayes = self
+                .members
+                .iter()
+                .filter(|member| self.votes.get((**member, id)).unwrap_or(false))
+                .count() as u32;
+            if ayes < self.quorum {
+                return Err(ZeitDAOError::NotEnoughVotesApproved);
+            }
+
+            let proposal = self.proposals[id as usize].clone();
+            self.dispatch_action(id, &proposal, 0)?;
+            self.executed.insert(id, &true);
+
+            self.env().emit_event(ProposalExecuted {
+                executor: self.env().caller(),
+                id,
+                action: proposal.selector,
+            });
+            Ok(())
+        }
 
         // region: DAO Config Functions
 
-        pub fn distribute(&mut self, balance: u128, target: AccountId) -> Result<(), ZeitDAOError> {
+        #[ink(message)]
+        pub fn distribute(
+            &mut self,
+            balance: u128,
+            target: AccountId,
+            currency_id: ZeitgeistAsset,
+        ) -> Result<(), ZeitDAOError> {
             self.only_self()?;
-            self.env()
-                .call_runtime(&RuntimeCall::AssetManager(AssetManagerCall::Transfer {
-                    dest: target.into(),
-                    currency_id: ZeitgeistAsset::Ztg,
-                    amount: balance,
-                }))
-                .map_err(Into::into)
+            self.distribute_impl(balance, target, currency_id)
         }
 
-        /*
-        AddMember(AccountId),
-        RemoveMember(AccountId),
-        RuntimeCall(StorableRuntimeAction),
-        Batch(Vec<DAOAction>),
-        */
+        /// Adds a new member to the DAO. Only callable by the contract itself; `execute`
+        /// applies an approved `DAOAction::AddMember` proposal directly, without this message.
+        #[ink(message)]
+        pub fn add_member(&mut self, member: AccountId) -> Result<(), ZeitDAOError> {
+            self.only_self()?;
+            self.add_member_impl(member);
+            Ok(())
+        }
+
+        /// Removes a member from the DAO. Only callable by the contract itself. Fails if
+        /// removing the member would leave `quorum` unreachable.
+        #[ink(message)]
+        pub fn remove_member(&mut self, member: AccountId) -> Result<(), ZeitDAOError> {
+            self.only_self()?;
+            self.remove_member_impl(member)
+        }
+
+        /// Changes the quorum required to execute a proposal. Only callable by the contract
+        /// itself. Fails if the new quorum could never be reached by the current members.
+        #[ink(message)]
+        pub fn change_quorum(&mut self, quorum: u32) -> Result<(), ZeitDAOError> {
+            self.only_self()?;
+            self.change_quorum_impl(quorum)
+        }
 
         // endregion
 
@@ -233,13 +350,24 @@ mod zeit_dao {
         /// Returns the information about a specific proposal. None if proposal does not exist.
         #[ink(message)]
         pub fn proposal(&self, id: u32) -> Option<StorableRuntimeAction> {
-            if self.proposals.len() >= id as usize {
+            if self.proposals.len() <= id as usize {
                 None
             } else {
                 Some(self.proposals[id as usize].clone())
             }
         }
 
+        /// Reports the contract's holdings of `currency_id`, read through the
+        /// `ZeitgeistExtension` chain extension rather than `call_runtime` (which can only
+        /// dispatch extrinsics, not query storage).
+        #[ink(message)]
+        pub fn asset_balance(&self, currency_id: ZeitgeistAsset) -> Result<u128, ZeitDAOError> {
+            self.env()
+                .extension()
+                .fetch_asset_balance(self.env().account_id(), currency_id)
+                .map_err(|_| ZeitDAOError::AssetBalanceQueryFailed)
+        }
+
         // endregion
 
         /* ================ PRIVATE / MODIFIERS ================ */
@@ -264,6 +392,168 @@ mod zeit_dao {
             }
             Ok(())
         }
+
+        /// Dispatches `call` under this instance's configured pallet indices (see
+        /// `RuntimeConfig`), so the same contract WASM works against either Zeitgeist
+        /// mainnet or Battery Station without recompiling.
+        fn dispatch_runtime_call(&self, call: RuntimeCall) -> Result<(), ZeitDAOError> {
+            self.env()
+                .call_runtime(&ConfiguredRuntimeCall {
+                    config: &self.runtime_config,
+                    call: &call,
+                })
+                .map_err(Into::into)
+        }
+
+        /// Dispatches the action selected by a proposal. Shared between `execute` and
+        /// `DAOAction::Batch`'s own recursive calls, aborting on the first error so a batch
+        /// cannot be partially applied. `id` is the top-level proposal id, carried through
+        /// for event emission even when recursing into a batch's inner actions. `depth` is
+        /// the current `Batch`-within-`Batch` nesting level, checked against
+        /// `MAX_BATCH_DEPTH` so a proposal can't be crafted to exhaust the call stack.
+        fn dispatch_action(
+            &mut self,
+            id: u32,
+            proposal: &StorableRuntimeAction,
+            depth: u8,
+        ) -> Result<(), ZeitDAOError> {
+            match &proposal.selector {
+                DAOAction::AddMember(member) => {
+                    self.add_member_impl(*member);
+                    Ok(())
+                }
+                DAOAction::RemoveMember(member) => self.remove_member_impl(*member),
+                DAOAction::ChangeQuorum(quorum) => self.change_quorum_impl(*quorum),
+                DAOAction::Batch(actions) => {
+                    if depth >= MAX_BATCH_DEPTH {
+                        return Err(ZeitDAOError::BatchNestedTooDeep);
+                    }
+                    if actions.len() > MAX_BATCH_LEN {
+                        return Err(ZeitDAOError::BatchTooLarge);
+                    }
+                    for action in actions {
+                        self.dispatch_action(id, action, depth + 1)?;
+                    }
+                    Ok(())
+                }
+                DAOAction::RemarkWithEvent => {
+                    let remark = <Vec<u8> as scale::Decode>::decode(&mut proposal.data.as_slice())
+                        .map_err(|_| ZeitDAOError::InvalidProposalData)?;
+                    self.dispatch_runtime_call(RuntimeCall::System(SystemCall::RemarkWithEvent {
+                        remark,
+                    }))
+                }
+                DAOAction::ReportMarket { market_id, outcome } => {
+                    self.dispatch_runtime_call(RuntimeCall::PredictionMarkets(
+                        PredictionMarketsCall::Report {
+                            market_id: *market_id,
+                            outcome: outcome.clone(),
+                        },
+                    ))
+                }
+                DAOAction::DisputeMarket { market_id } => {
+                    self.dispatch_runtime_call(RuntimeCall::PredictionMarkets(
+                        PredictionMarketsCall::Dispute {
+                            market_id: *market_id,
+                        },
+                    ))
+                }
+                DAOAction::ResolveMarket { market_id } => {
+                    self.dispatch_runtime_call(RuntimeCall::PredictionMarkets(
+                        PredictionMarketsCall::AdminMoveMarketToResolved {
+                            market_id: *market_id,
+                        },
+                    ))
+                }
+                DAOAction::Buy {
+                    market_id,
+                    asset_count,
+                    asset,
+                    amount_in,
+                    max_price,
+                    orders,
+                } => {
+                    self.dispatch_runtime_call(RuntimeCall::HybridRouter(HybridRouterCall::Buy {
+                        market_id: *market_id,
+                        asset_count: *asset_count,
+                        asset: asset.clone(),
+                        amount_in: *amount_in,
+                        max_price: *max_price,
+                        orders: orders.clone(),
+                    }))?;
+                    self.env().emit_event(PositionTraded {
+                        id,
+                        market_id: *market_id,
+                        asset: asset.clone(),
+                    });
+                    Ok(())
+                }
+                DAOAction::Distribute {
+                    balance,
+                    target,
+                    currency_id,
+                } => self.distribute_impl(*balance, *target, currency_id.clone()),
+                DAOAction::Sell {
+                    market_id,
+                    asset_count,
+                    asset,
+                    amount_in,
+                    max_price,
+                    orders,
+                } => {
+                    self.dispatch_runtime_call(RuntimeCall::HybridRouter(HybridRouterCall::Sell {
+                        market_id: *market_id,
+                        asset_count: *asset_count,
+                        asset: asset.clone(),
+                        amount_in: *amount_in,
+                        max_price: *max_price,
+                        orders: orders.clone(),
+                    }))?;
+                    self.env().emit_event(PositionTraded {
+                        id,
+                        market_id: *market_id,
+                        asset: asset.clone(),
+                    });
+                    Ok(())
+                }
+            }
+        }
+
+        fn add_member_impl(&mut self, member: AccountId) {
+            if !self.members.contains(&member) {
+                self.members.push(member);
+            }
+        }
+
+        fn remove_member_impl(&mut self, member: AccountId) -> Result<(), ZeitDAOError> {
+            let remaining = self.members.len() as u32 - self.members.contains(&member) as u32;
+            if self.quorum > remaining {
+                return Err(ZeitDAOError::QuorumExceedsMembers);
+            }
+            self.members.retain(|m| *m != member);
+            Ok(())
+        }
+
+        fn change_quorum_impl(&mut self, quorum: u32) -> Result<(), ZeitDAOError> {
+            if quorum > self.members.len() as u32 {
+                return Err(ZeitDAOError::QuorumExceedsMembers);
+            }
+            self.quorum = quorum;
+            Ok(())
+        }
+
+        fn distribute_impl(
+            &mut self,
+            balance: u128,
+            target: AccountId,
+            currency_id: ZeitgeistAsset,
+        ) -> Result<(), ZeitDAOError> {
+            self.dispatch_runtime_call(RuntimeCall::AssetManager(AssetManagerCall::Transfer {
+                dest: target.into(),
+                currency_id,
+                amount: balance,
+            }))
+        }
     }
 
     /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -278,7 +568,7 @@ mod zeit_dao {
         #[ink::test]
         fn initialze_with_members() {
             let x = vec![AccountId::from([0x01; 32]), AccountId::from([0x05; 32])];
-            let z = ZeitDao::new(1, x.clone());
+            let z = ZeitDao::new(1, x.clone(), RuntimeConfig::battery_station());
             assert_eq!(z.members(), x);
         }
 
@@ -287,18 +577,230 @@ mod zeit_dao {
             let z1 = ZeitDao::new(
                 2,
                 vec![AccountId::from([0x01; 32]), AccountId::from([0x05; 32])],
+                RuntimeConfig::battery_station(),
             );
             assert_eq!(z1.is_member(), true);
 
             let z2: ZeitDao = ZeitDao::new(
                 2,
                 vec![AccountId::from([0x09; 32]), AccountId::from([0x05; 32])],
+                RuntimeConfig::battery_station(),
             );
             assert_eq!(z2.is_member(), false);
         }
+
+        fn new_dao(quorum: u32, members: Vec<AccountId>) -> ZeitDao {
+            ZeitDao::new(quorum, members, RuntimeConfig::battery_station())
+        }
+
+        /// A proposal that doesn't touch `call_runtime`, so it can execute inside the
+        /// off-chain test environment without a chain extension/runtime mock.
+        fn add_member_proposal(member: AccountId) -> StorableRuntimeAction {
+            StorableRuntimeAction {
+                selector: DAOAction::AddMember(member),
+                data: Vec::new(),
+            }
+        }
+
+        #[ink::test]
+        fn execute_fails_for_nonexistent_proposal() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+            assert_eq!(dao.execute(0), Err(ZeitDAOError::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn execute_fails_when_quorum_not_met() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(2, vec![accounts.alice, accounts.bob]);
+            let id = dao.propose(add_member_proposal(accounts.eve)).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            dao.vote(id, true).unwrap();
+
+            assert_eq!(dao.execute(id), Err(ZeitDAOError::NotEnoughVotesApproved));
+        }
+
+        #[ink::test]
+        fn execute_succeeds_once_quorum_met() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(2, vec![accounts.alice, accounts.bob]);
+            let id = dao.propose(add_member_proposal(accounts.eve)).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            dao.vote(id, true).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            dao.vote(id, true).unwrap();
+
+            assert_eq!(dao.execute(id), Ok(()));
+            assert!(dao.members().contains(&accounts.eve));
+        }
+
+        #[ink::test]
+        fn execute_fails_when_replayed() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+            let id = dao.propose(add_member_proposal(accounts.eve)).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            dao.vote(id, true).unwrap();
+            dao.execute(id).unwrap();
+
+            assert_eq!(dao.execute(id), Err(ZeitDAOError::ProposalAlreadyExecuted));
+        }
+
+        #[ink::test]
+        fn add_member_rejected_unless_called_by_self() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                dao.add_member(accounts.bob),
+                Err(ZeitDAOError::OnlySelfAllowed)
+            );
+        }
+
+        #[ink::test]
+        fn remove_member_rejected_if_quorum_would_be_unreachable() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(2, vec![accounts.alice, accounts.bob]);
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                dao.remove_member(accounts.bob),
+                Err(ZeitDAOError::QuorumExceedsMembers)
+            );
+        }
+
+        #[ink::test]
+        fn change_quorum_rejected_if_above_member_count() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+            ink::env::test::set_callee::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                dao.change_quorum(5),
+                Err(ZeitDAOError::QuorumExceedsMembers)
+            );
+        }
+
+        fn batch_proposal(actions: Vec<StorableRuntimeAction>) -> StorableRuntimeAction {
+            StorableRuntimeAction {
+                selector: DAOAction::Batch(actions),
+                data: Vec::new(),
+            }
+        }
+
+        #[ink::test]
+        fn batch_rejected_when_too_large() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+            let actions: Vec<_> = (0..(MAX_BATCH_LEN + 1) as u8)
+                .map(|i| add_member_proposal(AccountId::from([i; 32])))
+                .collect();
+            let id = dao.propose(batch_proposal(actions)).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            dao.vote(id, true).unwrap();
+
+            assert_eq!(dao.execute(id), Err(ZeitDAOError::BatchTooLarge));
+        }
+
+        #[ink::test]
+        fn batch_rejected_when_nested_too_deep() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = new_dao(1, vec![accounts.alice]);
+
+            // Wraps a single `AddMember` in `MAX_BATCH_DEPTH + 1` nested `Batch`es, one more
+            // level than `dispatch_action` allows.
+            let mut nested = add_member_proposal(accounts.eve);
+            for _ in 0..(MAX_BATCH_DEPTH + 1) {
+                nested = batch_proposal(vec![nested]);
+            }
+            let id = dao.propose(nested).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            dao.vote(id, true).unwrap();
+
+            assert_eq!(dao.execute(id), Err(ZeitDAOError::BatchNestedTooDeep));
+        }
+
+        #[ink::test]
+        fn configured_runtime_call_prefixes_pallet_index() {
+            let call = RuntimeCall::AssetManager(AssetManagerCall::Transfer {
+                dest: AccountId::from([0x01; 32]).into(),
+                currency_id: ZeitgeistAsset::Ztg,
+                amount: 1,
+            });
+
+            let battery_station = RuntimeConfig::battery_station();
+            let encoded = scale::Encode::encode(&ConfiguredRuntimeCall {
+                config: &battery_station,
+                call: &call,
+            });
+            assert_eq!(encoded[0], battery_station.asset_manager_index);
+
+            let zeitgeist = RuntimeConfig::zeitgeist();
+            let encoded = scale::Encode::encode(&ConfiguredRuntimeCall {
+                config: &zeitgeist,
+                call: &call,
+            });
+            assert_eq!(encoded[0], zeitgeist.asset_manager_index);
+        }
     }
 }
 
+// region: Chain Extension
+
+/// `pallet-contracts`'s `call_runtime` can only dispatch extrinsics, not read pallet
+/// storage, so `asset_balance` can't answer through the same `RuntimeCall` machinery as
+/// every other message in this contract. Reading the `AssetManager` (ORML `Currencies`)
+/// free balance instead goes through a chain extension the runtime must register, the
+/// same pattern `ink-examples`' `rand-extension` uses to read randomness.
+#[ink::chain_extension]
+pub trait ZeitgeistExtension {
+    type ErrorCode = ZeitgeistExtensionError;
+
+    #[ink(extension = 1101)]
+    fn fetch_asset_balance(account: AccountId, asset: ZeitgeistAsset) -> u128;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ZeitgeistExtensionError {
+    FailedToFetchBalance,
+}
+
+impl ink::env::chain_extension::FromStatusCode for ZeitgeistExtensionError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            1 => Err(Self::FailedToFetchBalance),
+            _ => panic!("Unknown status code from `ZeitgeistExtension`."),
+        }
+    }
+}
+
+/// Wires `ZeitgeistExtension` in as this contract's chain extension; otherwise identical
+/// to `ink::env::DefaultEnvironment`.
+#[derive(Clone)]
+pub enum ZeitDaoEnvironment {}
+
+impl ink::env::Environment for ZeitDaoEnvironment {
+    const MAX_EVENT_TOPICS: usize =
+        <ink::env::DefaultEnvironment as ink::env::Environment>::MAX_EVENT_TOPICS;
+
+    type AccountId = <ink::env::DefaultEnvironment as ink::env::Environment>::AccountId;
+    type Balance = <ink::env::DefaultEnvironment as ink::env::Environment>::Balance;
+    type Hash = <ink::env::DefaultEnvironment as ink::env::Environment>::Hash;
+    type Timestamp = <ink::env::DefaultEnvironment as ink::env::Environment>::Timestamp;
+    type BlockNumber = <ink::env::DefaultEnvironment as ink::env::Environment>::BlockNumber;
+
+    type ChainExtension = ZeitgeistExtension;
+}
+
+// endregion
+
 // region: Runtime Calls
 
 // TODO: only these calls are allowed https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/runtime/battery-station/src/lib.rs#L121-L164
@@ -313,21 +815,93 @@ mod zeit_dao {
 /// You can investigate the full `RuntimeCall` definition by either expanding
 /// `construct_runtime!` macro application or by using secondary tools for reading chain
 /// metadata, like `subxt`.
-#[derive(scale::Encode, scale::Decode)]
+///
+/// Zeitgeist's mainnet and Battery Station testnet runtimes are separate crates with
+/// independent `construct_runtime!` orderings, so the pallet index for a given variant
+/// differs between them. Rather than a compile-time feature (which would need a
+/// `Cargo.toml` this workspace doesn't have), the index actually dispatched is looked up
+/// at runtime from the `RuntimeConfig` stored on `ZeitDao` — see `ConfiguredRuntimeCall`.
+/// This lets the same contract WASM be deployed against either chain without recompiling
+/// a fork.
 enum RuntimeCall {
-    /// This index can be found by investigating runtime configuration. You can check the
-    /// pallet order inside `construct_runtime!` block and read the position of your
-    /// pallet (0-based).
-    ///
-    /// https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/runtime/common/src/lib.rs#L254-L363
-    ///
-    /// [See here for more.](https://substrate.stackexchange.com/questions/778/how-to-get-pallet-index-u8-of-a-pallet-in-runtime)
-    #[codec(index = 0)]
     System(SystemCall),
-    #[codec(index = 40)]
     AssetManager(AssetManagerCall),
-    #[codec(index = 57)]
     PredictionMarkets(PredictionMarketsCall),
+    HybridRouter(HybridRouterCall),
+}
+
+impl RuntimeCall {
+    /// The pallet index to dispatch this call under, per `config`.
+    fn pallet_index(&self, config: &RuntimeConfig) -> u8 {
+        match self {
+            RuntimeCall::System(_) => config.system_index,
+            RuntimeCall::AssetManager(_) => config.asset_manager_index,
+            RuntimeCall::PredictionMarkets(_) => config.prediction_markets_index,
+            RuntimeCall::HybridRouter(_) => config.hybrid_router_index,
+        }
+    }
+}
+
+/// The pallet indices a `ZeitDao` instance dispatches `RuntimeCall`s under. Stored at
+/// construction (see `ZeitDao::new`) so one contract WASM can be deployed against either
+/// Zeitgeist mainnet or its Battery Station testnet, whose `construct_runtime!` orderings
+/// differ, without recompiling a fork for each.
+#[derive(Debug, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub struct RuntimeConfig {
+    pub system_index: u8,
+    pub asset_manager_index: u8,
+    pub prediction_markets_index: u8,
+    pub hybrid_router_index: u8,
+}
+
+impl RuntimeConfig {
+    /// Pallet indices confirmed against the Battery Station testnet runtime.
+    ///
+    /// https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/runtime/battery-station/src/lib.rs#L121-L164
+    pub const fn battery_station() -> Self {
+        Self {
+            system_index: 0,
+            asset_manager_index: 40,
+            prediction_markets_index: 57,
+            hybrid_router_index: 69,
+        }
+    }
+
+    /// Pallet indices for the Zeitgeist mainnet runtime. Unlike `battery_station()`, these
+    /// are not confirmed; double-check them against the live mainnet chain metadata before
+    /// relying on them for a deployment.
+    pub const fn zeitgeist() -> Self {
+        Self {
+            system_index: 0,
+            asset_manager_index: 41,
+            prediction_markets_index: 56,
+            hybrid_router_index: 69,
+        }
+    }
+}
+
+/// Encodes a `RuntimeCall` the way `pallet-contracts`'s `call_runtime` expects: the
+/// dispatched pallet's index byte (looked up from `config`), followed by the call's own
+/// SCALE encoding (which already carries its `#[codec(index = _)]` call index).
+struct ConfiguredRuntimeCall<'a> {
+    config: &'a RuntimeConfig,
+    call: &'a RuntimeCall,
+}
+
+impl<'a> scale::Encode for ConfiguredRuntimeCall<'a> {
+    fn encode_to<T: scale::Output + ?Sized>(&self, dest: &mut T) {
+        dest.push_byte(self.call.pallet_index(self.config));
+        match self.call {
+            RuntimeCall::System(c) => c.encode_to(dest),
+            RuntimeCall::AssetManager(c) => c.encode_to(dest),
+            RuntimeCall::PredictionMarkets(c) => c.encode_to(dest),
+            RuntimeCall::HybridRouter(c) => c.encode_to(dest),
+        }
+    }
 }
 
 #[derive(scale::Encode, scale::Decode)]
@@ -375,22 +949,103 @@ enum PredictionMarketsCall {
         amount: u128,
         weights: Vec<u128>,
     },
+    /// Reports the winning outcome of a market. Only meaningful while the DAO is the
+    /// market's configured oracle (see `test_create_market`).
+    ///
+    /// Index taken from source order in `zrml-prediction-markets`'s `#[pallet::call]`:
+    /// https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/zrml/prediction-markets/src/lib.rs
+    /// Unverified against live chain metadata — double-check before relying on it for a
+    /// deployment, same caveat as `RuntimeConfig::zeitgeist()`.
+    #[codec(index = 12)]
+    Report {
+        market_id: u128,
+        outcome: OutcomeReport,
+    },
+    /// Opens a dispute against the currently reported outcome.
+    ///
+    /// Index taken from source order in `zrml-prediction-markets`'s `#[pallet::call]`:
+    /// https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/zrml/prediction-markets/src/lib.rs
+    /// Unverified against live chain metadata — double-check before relying on it for a
+    /// deployment, same caveat as `RuntimeConfig::zeitgeist()`.
+    #[codec(index = 6)]
+    Dispute { market_id: u128 },
+    /// Moves a disputed market through to resolution once its
+    /// `MarketDisputeMechanism::Authorized` path has ruled on it.
+    ///
+    /// Index taken from source order in `zrml-prediction-markets`'s `#[pallet::call]`:
+    /// https://github.com/zeitgeistpm/zeitgeist/blob/3d9bbff91219bb324f047427224ee318061a6d43/zrml/prediction-markets/src/lib.rs
+    /// Unverified against live chain metadata — double-check before relying on it for a
+    /// deployment, same caveat as `RuntimeConfig::zeitgeist()`.
+    #[codec(index = 2)]
+    AdminMoveMarketToResolved { market_id: u128 },
+}
+
+/// The outcome reported for a market, matching the shape of its `MarketType`.
+#[derive(Debug, scale::Encode, scale::Decode, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub enum OutcomeReport {
+    Categorical(u16),
+    Scalar(u128),
+}
+
+/// The combined AMM + central-limit-order-book routing pallet that lets an account take
+/// positions in a market's outcome assets.
+#[derive(scale::Encode, scale::Decode)]
+enum HybridRouterCall {
+    Buy {
+        market_id: u128,
+        asset_count: u16,
+        asset: ZeitgeistAsset,
+        #[codec(compact)]
+        amount_in: u128,
+        #[codec(compact)]
+        max_price: u128,
+        orders: Vec<u128>,
+    },
+    Sell {
+        market_id: u128,
+        asset_count: u16,
+        asset: ZeitgeistAsset,
+        #[codec(compact)]
+        amount_in: u128,
+        #[codec(compact)]
+        max_price: u128,
+        orders: Vec<u128>,
+    },
 }
 
 // endregion
 
 // region: Zeitgeist Types
 
-#[derive(scale::Encode, scale::Decode, Clone, PartialEq)]
+#[derive(Debug, scale::Encode, scale::Decode, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
 enum ZeitgeistAsset {
-    CategoricalOutcome, //(MI, CategoryIndex),
-    ScalarOutcome,      //(MI, ScalarPosition),
+    CategoricalOutcome(u128, u16),
+    ScalarOutcome(u128, ScalarPosition),
     CombinatorialOutcome,
     PoolShare, //(SerdeWrapper<PoolId>),
     Ztg,       // default
     ForeignAsset(u32),
 }
 
+/// Which side of a scalar market's range an outcome token represents.
+#[derive(Debug, scale::Encode, scale::Decode, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "std",
+    derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+)]
+pub enum ScalarPosition {
+    Long,
+    Short,
+}
+
 #[derive(scale::Encode, scale::Decode, Clone, PartialEq)]
 pub enum MarketDisputeMechanism {
     Authorized,